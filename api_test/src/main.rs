@@ -17,7 +17,10 @@ fn main() {
     }
 
     loop {
-        let executed_op = kpu.step();
+        let executed_op = match kpu.step() {
+            Ok(op) => op,
+            Err(trap) => panic!("Trap: {}", trap),
+        };
         println!("{}", executed_op);
 
         if let Op::Halt = executed_op {
@@ -0,0 +1,295 @@
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::Display;
+#[cfg(feature = "std")]
+use std::error::Error;
+
+use crate::{Op, Reg, Trap, OP_SIZE};
+
+/// Assembles `kasm` source text into a sequence of [`Op`]s, following the syntax and encoding
+/// documented on each [`Op`] variant
+pub fn assemble(src: &str) -> Result<Vec<Op>, AsmError> {
+    let mut ops = Vec::new();
+
+    for (line_index, line_str) in src.lines().enumerate() {
+        let line = line_index + 1;
+        let trimmed = line_str.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+
+        ops.push(parse_line(trimmed, line)?);
+    }
+
+    return Ok(ops);
+}
+
+/// Disassembles raw `.text` bytes back into a sequence of [`Op`]s, the exact inverse of
+/// [`Op::bytes`]
+pub fn disassemble(bytes: &[u8]) -> Result<Vec<Op>, Trap> {
+    let mut ops = Vec::with_capacity(bytes.len() / OP_SIZE);
+    for chunk in bytes.chunks_exact(OP_SIZE) {
+        let op_bytes: &[u8; OP_SIZE] = chunk.try_into().expect("chunk is exactly OP_SIZE bytes long");
+        ops.push(Op::decode(op_bytes)?);
+    }
+
+    return Ok(ops);
+}
+
+fn parse_line(trimmed: &str, line: usize) -> Result<Op, AsmError> {
+    let mut parts = trimmed.splitn(2, char::is_whitespace);
+    let mnemonic = parts.next().unwrap_or("");
+    let rest = parts.next().unwrap_or("").trim();
+    let operands: Vec<&str> =
+        if rest.is_empty() { Vec::new() } else { rest.split(',').map(str::trim).collect() };
+
+    return match mnemonic {
+        "nop" => {
+            expect_operand_count(&operands, 0, line, trimmed)?;
+            Ok(Op::Nop)
+        }
+        "halt" => {
+            expect_operand_count(&operands, 0, line, trimmed)?;
+            Ok(Op::Halt)
+        }
+        "ret" => {
+            expect_operand_count(&operands, 0, line, trimmed)?;
+            Ok(Op::Ret)
+        }
+        "move" => parse_move(&operands, line, trimmed),
+        "add" => parse_alu(&operands, line, trimmed, |dst, src| Op::AddRegReg { dst, src }, |dst, imm| {
+            Op::AddRegImm { dst, imm }
+        }),
+        "sub" => parse_alu(&operands, line, trimmed, |dst, src| Op::SubRegReg { dst, src }, |dst, imm| {
+            Op::SubRegImm { dst, imm }
+        }),
+        "mul" => parse_alu(&operands, line, trimmed, |dst, src| Op::MulRegReg { dst, src }, |dst, imm| {
+            Op::MulRegImm { dst, imm }
+        }),
+        "div" => parse_alu(&operands, line, trimmed, |dst, src| Op::DivRegReg { dst, src }, |dst, imm| {
+            Op::DivRegImm { dst, imm }
+        }),
+        "and" => parse_alu(&operands, line, trimmed, |dst, src| Op::AndRegReg { dst, src }, |dst, imm| {
+            Op::AndRegImm { dst, imm }
+        }),
+        "or" => parse_alu(&operands, line, trimmed, |dst, src| Op::OrRegReg { dst, src }, |dst, imm| {
+            Op::OrRegImm { dst, imm }
+        }),
+        "xor" => parse_alu(&operands, line, trimmed, |dst, src| Op::XorRegReg { dst, src }, |dst, imm| {
+            Op::XorRegImm { dst, imm }
+        }),
+        "shl" => parse_alu(&operands, line, trimmed, |dst, src| Op::ShlRegReg { dst, src }, |dst, imm| {
+            Op::ShlRegImm { dst, imm }
+        }),
+        "shr" => parse_alu(&operands, line, trimmed, |dst, src| Op::ShrRegReg { dst, src }, |dst, imm| {
+            Op::ShrRegImm { dst, imm }
+        }),
+        "jump" => parse_jump(&operands, line, trimmed, |target_high, target_low| Op::Jump {
+            target_high,
+            target_low,
+        }),
+        "jz" => parse_jump(&operands, line, trimmed, |target_high, target_low| Op::JumpZero {
+            target_high,
+            target_low,
+        }),
+        "jnz" => parse_jump(&operands, line, trimmed, |target_high, target_low| Op::JumpNotZero {
+            target_high,
+            target_low,
+        }),
+        "jc" => parse_jump(&operands, line, trimmed, |target_high, target_low| Op::JumpCarry {
+            target_high,
+            target_low,
+        }),
+        "js" => parse_jump(&operands, line, trimmed, |target_high, target_low| Op::JumpSign {
+            target_high,
+            target_low,
+        }),
+        "call" => parse_jump(&operands, line, trimmed, |target_high, target_low| Op::Call {
+            target_high,
+            target_low,
+        }),
+        _ => Err(AsmError::UnknownMnemonic {
+            line,
+            column: column_of(trimmed, mnemonic),
+            mnemonic: mnemonic.to_string(),
+        }),
+    };
+}
+
+fn parse_move(operands: &[&str], line: usize, line_text: &str) -> Result<Op, AsmError> {
+    expect_operand_count(operands, 2, line, line_text)?;
+    let dst_text = operands[0];
+    let src_text = operands[1];
+
+    if let Some(addr_text) = memory_operand(dst_text) {
+        if let Ok(addr) = parse_register(addr_text, line, line_text) {
+            let src = parse_register(src_text, line, line_text)?;
+            return Ok(Op::MoveMemRegReg { addr, src });
+        }
+
+        let offset = parse_u16(addr_text, line, line_text)?;
+        let [mem_high, mem_low] = offset.to_be_bytes();
+        return match parse_register(src_text, line, line_text) {
+            Ok(src) => Ok(Op::MoveMemReg { mem_high, mem_low, src }),
+            Err(_) => {
+                let imm = parse_u8(src_text, line, line_text)?;
+                Ok(Op::MoveMemImm { mem_high, mem_low, imm })
+            }
+        };
+    }
+
+    let dst = parse_register(dst_text, line, line_text)?;
+    if let Some(addr_text) = memory_operand(src_text) {
+        if let Ok(addr) = parse_register(addr_text, line, line_text) {
+            return Ok(Op::MoveRegMemReg { dst, addr });
+        }
+
+        let offset = parse_u16(addr_text, line, line_text)?;
+        let [mem_high, mem_low] = offset.to_be_bytes();
+        return Ok(Op::MoveRegMem { dst, mem_high, mem_low });
+    }
+    return match parse_register(src_text, line, line_text) {
+        Ok(src) => Ok(Op::MoveRegReg { dst, src }),
+        Err(_) => match parse_u8(src_text, line, line_text) {
+            Ok(imm) => Ok(Op::MoveRegImm { dst, imm }),
+            Err(_) => {
+                let imm = parse_u16(src_text, line, line_text)?;
+                let [imm_high, imm_low] = imm.to_be_bytes();
+                Ok(Op::MoveRegImm16 { dst, imm_high, imm_low })
+            }
+        },
+    };
+}
+
+fn parse_alu(
+    operands: &[&str],
+    line: usize,
+    line_text: &str,
+    reg_reg: impl FnOnce(Reg, Reg) -> Op,
+    reg_imm: impl FnOnce(Reg, u8) -> Op,
+) -> Result<Op, AsmError> {
+    expect_operand_count(operands, 2, line, line_text)?;
+    let dst = parse_register(operands[0], line, line_text)?;
+    return match parse_register(operands[1], line, line_text) {
+        Ok(src) => Ok(reg_reg(dst, src)),
+        Err(_) => {
+            let imm = parse_u8(operands[1], line, line_text)?;
+            Ok(reg_imm(dst, imm))
+        }
+    };
+}
+
+fn parse_jump(
+    operands: &[&str],
+    line: usize,
+    line_text: &str,
+    make: impl FnOnce(u8, u8) -> Op,
+) -> Result<Op, AsmError> {
+    expect_operand_count(operands, 1, line, line_text)?;
+    let target = parse_u16(operands[0], line, line_text)?;
+    let [target_high, target_low] = target.to_be_bytes();
+    return Ok(make(target_high, target_low));
+}
+
+/// strips the surrounding `[`/`]` off of a memory operand like `[19]`, returning the inner text
+fn memory_operand(text: &str) -> Option<&str> {
+    return text.strip_prefix('[')?.strip_suffix(']');
+}
+
+fn parse_register(text: &str, line: usize, line_text: &str) -> Result<Reg, AsmError> {
+    return match text {
+        "r0" => Ok(Reg::R0),
+        "r1" => Ok(Reg::R1),
+        "r2" => Ok(Reg::R2),
+        "r3" => Ok(Reg::R3),
+        "ip" => Ok(Reg::IP),
+        _ => Err(AsmError::UnknownRegister {
+            line,
+            column: column_of(line_text, text),
+            register: text.to_string(),
+        }),
+    };
+}
+
+fn parse_integer(text: &str) -> Option<i64> {
+    if let Some(hex) = text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+        return i64::from_str_radix(hex, 16).ok();
+    }
+    return text.parse::<i64>().ok();
+}
+
+fn parse_u8(text: &str, line: usize, line_text: &str) -> Result<u8, AsmError> {
+    let Some(value) = parse_integer(text) else {
+        return Err(AsmError::InvalidImmediate { line, column: column_of(line_text, text), text: text.to_string() });
+    };
+    if !(-128..=255).contains(&value) {
+        return Err(AsmError::ImmediateOutOfRange { line, column: column_of(line_text, text), value });
+    }
+    return Ok(value as u8);
+}
+
+fn parse_u16(text: &str, line: usize, line_text: &str) -> Result<u16, AsmError> {
+    let Some(value) = parse_integer(text) else {
+        return Err(AsmError::InvalidImmediate { line, column: column_of(line_text, text), text: text.to_string() });
+    };
+    if !(0..=u16::MAX as i64).contains(&value) {
+        return Err(AsmError::ImmediateOutOfRange { line, column: column_of(line_text, text), value });
+    }
+    return Ok(value as u16);
+}
+
+fn expect_operand_count(operands: &[&str], expected: usize, line: usize, line_text: &str) -> Result<(), AsmError> {
+    if operands.len() != expected {
+        return Err(AsmError::WrongOperandCount {
+            line,
+            column: line_text.len() + 1,
+            expected,
+            found: operands.len(),
+        });
+    }
+    return Ok(());
+}
+
+/// finds the 1-based column of `needle`'s first occurrence within `line_text`, falling back to
+/// the start of the line when it cannot be found (e.g. an empty mnemonic)
+fn column_of(line_text: &str, needle: &str) -> usize {
+    return line_text.find(needle).map(|byte_offset| byte_offset + 1).unwrap_or(1);
+}
+
+/// Errors produced while [`assemble`]ing `kasm` source text, each pinpointing the offending
+/// line and column
+#[derive(Debug, Clone)]
+pub enum AsmError {
+    UnknownMnemonic { line: usize, column: usize, mnemonic: String },
+    UnknownRegister { line: usize, column: usize, register: String },
+    InvalidImmediate { line: usize, column: usize, text: String },
+    ImmediateOutOfRange { line: usize, column: usize, value: i64 },
+    WrongOperandCount { line: usize, column: usize, expected: usize, found: usize },
+}
+
+impl Display for AsmError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        return match self {
+            Self::UnknownMnemonic { line, column, mnemonic } => {
+                write!(f, "{}:{}: unknown mnemonic '{}'", line, column, mnemonic)
+            }
+            Self::UnknownRegister { line, column, register } => {
+                write!(f, "{}:{}: unknown register '{}'", line, column, register)
+            }
+            Self::InvalidImmediate { line, column, text } => {
+                write!(f, "{}:{}: invalid immediate '{}'", line, column, text)
+            }
+            Self::ImmediateOutOfRange { line, column, value } => {
+                write!(f, "{}:{}: immediate '{}' is out of range", line, column, value)
+            }
+            Self::WrongOperandCount { line, column, expected, found } => {
+                write!(f, "{}:{}: expected {} operand(s), found {}", line, column, expected, found)
+            }
+        };
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for AsmError {}
@@ -1,11 +1,32 @@
-use std::{
-    error::Error, fmt::{Debug, Display}, mem::{size_of, transmute}
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::needless_return)]
+
+extern crate alloc;
+
+use alloc::{boxed::Box, vec};
+use core::{
+    fmt::{Debug, Display}, mem::{size_of, transmute}
 };
+#[cfg(feature = "std")]
+use std::error::Error;
+
+mod assembler;
+pub use assembler::{AsmError, assemble, disassemble};
+
+/// the byte-addressable view of a [`Register`]'s 16bit value, overlapping `full` the same way
+/// the Game Boy's 16bit register pairs overlap their 8bit halves
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct RegisterBytes {
+    pub low: u8,
+    pub high: u8,
+}
 
 #[repr(C)]
 #[derive(Clone, Copy)]
 pub union Register {
-    pub full: u8,
+    pub full: u16,
+    pub bytes: RegisterBytes,
 }
 
 impl Default for Register {
@@ -15,7 +36,7 @@ impl Default for Register {
 }
 
 impl Debug for Register {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         unsafe {
             #[rustfmt::skip]
             return write!(f,
@@ -28,13 +49,67 @@ impl Debug for Register {
     }
 }
 
-#[derive(Debug, Clone, Copy, Default)]
+#[derive(Debug, Clone, Copy)]
 pub(crate) struct Registers {
     pub(crate) r0: Register,
     pub(crate) r1: Register,
     pub(crate) r2: Register,
     pub(crate) r3: Register,
     pub(crate) ip: Register,
+
+    /// status flags updated after every ALU operation, see [`FLAG_ZERO`], [`FLAG_CARRY`],
+    /// [`FLAG_OVERFLOW`] and [`FLAG_SIGN`]
+    pub(crate) flags: Register,
+
+    /// stack pointer: a full 16bit index into `.data`, used by `Call`/`Ret` to save/restore the
+    /// return `ip`, see [`STACK_TOP`]
+    pub(crate) sp: Register,
+}
+
+impl Default for Registers {
+    fn default() -> Self {
+        return Self {
+            r0: Register::default(),
+            r1: Register::default(),
+            r2: Register::default(),
+            r3: Register::default(),
+            ip: Register::default(),
+            flags: Register::default(),
+            sp: Register { full: STACK_TOP },
+        };
+    }
+}
+
+/// initial/reset value of `sp`: the call stack grows downward from here, one byte at a time,
+/// spanning the whole `.data` section except offset `0` (a push with `sp` at `0` raises
+/// [`Trap::StackOverflow`]) now that `sp` can hold a full `.data` offset
+pub(crate) const STACK_TOP: u16 = (DATA_SIZE - 1) as u16;
+
+/// set when the result of the last ALU operation was `0`
+pub(crate) const FLAG_ZERO: u8 = 0b0000_0001;
+
+/// set when the last ALU operation wrapped around as an unsigned integer
+pub(crate) const FLAG_CARRY: u8 = 0b0000_0010;
+
+/// set when the last ALU operation wrapped around as a signed integer
+pub(crate) const FLAG_OVERFLOW: u8 = 0b0000_0100;
+
+/// set when bit 7 of the result of the last ALU operation was set
+pub(crate) const FLAG_SIGN: u8 = 0b0000_1000;
+
+/// the arithmetic/logic operation performed by an ALU [`Op`] variant, shared between its
+/// `RegReg` and `RegImm` forms
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum AluOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    And,
+    Or,
+    Xor,
+    Shl,
+    Shr,
 }
 
 
@@ -47,8 +122,23 @@ pub enum Reg {
     IP,
 }
 
+impl Reg {
+    /// Decodes a register nibble (the low 4 bits of an opcode byte), returning `None` for
+    /// nibble values that do not name a register
+    fn decode(nibble: u8) -> Option<Self> {
+        return match nibble {
+            0b0000 => Some(Self::R0),
+            0b0001 => Some(Self::R1),
+            0b0010 => Some(Self::R2),
+            0b0011 => Some(Self::R3),
+            0b0100 => Some(Self::IP),
+            _ => None,
+        };
+    }
+}
+
 impl Display for Reg {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         return match self {
             Self::R0 => write!(f, "r0"),
             Self::R1 => write!(f, "r1"),
@@ -85,6 +175,21 @@ impl Default for Memory {
 pub struct Kpu {
     pub(crate) reg: Registers,
     pub(crate) mem: Memory,
+
+    /// set once a trap fires or `Op::Halt` is executed, so further calls to `step` do not
+    /// re-execute past the faulting/halting instruction
+    pub(crate) halted: bool,
+
+    /// the trap that halted the machine, if any; kept around so callers that call `step` again
+    /// after a trap still get the same trap back instead of garbage state
+    pub(crate) trap: Option<Trap>,
+
+    /// the number of instructions successfully decoded and executed since the last [`reset`];
+    /// [`run`] counts its instruction budget separately, per call
+    ///
+    /// [`reset`]: Kpu::reset
+    /// [`run`]: Kpu::run
+    pub(crate) cycles: u64,
 }
 
 impl Kpu {
@@ -98,7 +203,12 @@ impl Kpu {
         self.reg.r2.full = 0;
         self.reg.r3.full = 0;
         self.reg.ip.full = 0;
+        self.reg.flags.full = 0;
+        self.reg.sp.full = STACK_TOP;
         self.mem.bytes.fill(0);
+        self.halted = false;
+        self.trap = None;
+        self.cycles = 0;
     }
 
     pub fn load(&mut self, ops: &[Op]) -> Result<(), LoadError> {
@@ -114,6 +224,41 @@ impl Kpu {
         return Ok(());
     }
 
+    /// reads the full 16bit value of `reg`, for hosts inspecting the KPU between
+    /// [`step`]/[`run`] calls
+    ///
+    /// [`step`]: Kpu::step
+    /// [`run`]: Kpu::run
+    pub fn register(&self, reg: Reg) -> u16 {
+        return unsafe { self.reg(reg).full };
+    }
+
+    /// reads the current status flags, see [`FLAG_ZERO`], [`FLAG_CARRY`], [`FLAG_OVERFLOW`] and
+    /// [`FLAG_SIGN`]
+    pub fn flags(&self) -> u8 {
+        return unsafe { self.reg.flags.bytes.low };
+    }
+
+    /// reads the current call-stack pointer, see [`STACK_TOP`]
+    pub fn stack_pointer(&self) -> u16 {
+        return unsafe { self.reg.sp.full };
+    }
+
+    /// reads the `.data` section, for hosts inspecting memory between [`step`]/[`run`] calls
+    ///
+    /// [`step`]: Kpu::step
+    /// [`run`]: Kpu::run
+    pub fn data(&self) -> &[u8; DATA_SIZE] {
+        return self.mem.data;
+    }
+
+    /// the number of instructions successfully executed since the last [`reset`]
+    ///
+    /// [`reset`]: Kpu::reset
+    pub fn cycles(&self) -> u64 {
+        return self.cycles;
+    }
+
     pub(crate) fn reg(&self, reg: Reg) -> &Register {
         return match reg {
             Reg::R0 => &self.reg.r0,
@@ -134,37 +279,400 @@ impl Kpu {
         };
     }
 
-    // TODO(stefano): check for overlflow of ip register
-    pub fn step(&mut self) -> Op {
+    /// raises `trap`, halting the machine so it can be inspected by the caller instead of
+    /// crashing
+    fn fault(&mut self, trap: Trap) -> Result<Op, Trap> {
+        self.halted = true;
+        self.trap = Some(trap);
+        return Err(trap);
+    }
+
+    /// performs `alu_op` on `dst` and `rhs`, storing the result back into `dst` and updating
+    /// [`FLAG_ZERO`], [`FLAG_CARRY`], [`FLAG_OVERFLOW`] and [`FLAG_SIGN`] accordingly
+    fn execute_alu(&mut self, alu_op: AluOp, dst: Reg, rhs: u8) -> Result<(), Trap> {
+        let lhs = unsafe { self.reg(dst).bytes.low };
+
+        let (result, carry, overflow) = match alu_op {
+            AluOp::Add => {
+                let (result, carry) = lhs.overflowing_add(rhs);
+                let (_, overflow) = (lhs as i8).overflowing_add(rhs as i8);
+                (result, carry, overflow)
+            }
+            AluOp::Sub => {
+                let (result, carry) = lhs.overflowing_sub(rhs);
+                let (_, overflow) = (lhs as i8).overflowing_sub(rhs as i8);
+                (result, carry, overflow)
+            }
+            AluOp::Mul => {
+                let (result, carry) = lhs.overflowing_mul(rhs);
+                let (_, overflow) = (lhs as i8).overflowing_mul(rhs as i8);
+                (result, carry, overflow)
+            }
+            AluOp::Div => {
+                if rhs == 0 {
+                    return Err(Trap::DivisionByZero);
+                }
+                (lhs / rhs, false, false)
+            }
+            AluOp::And => (lhs & rhs, false, false),
+            AluOp::Or => (lhs | rhs, false, false),
+            AluOp::Xor => (lhs ^ rhs, false, false),
+            AluOp::Shl => {
+                let shift = (rhs as u32).min(8);
+                let wide = (lhs as u16) << shift;
+                (wide as u8, wide > 0xFF, false)
+            }
+            AluOp::Shr => {
+                let shift = (rhs as u32).min(8);
+                let shifted_out_mask = ((1u16 << shift) - 1) as u8;
+                (lhs.checked_shr(shift).unwrap_or(0), lhs & shifted_out_mask != 0, false)
+            }
+        };
+
+        self.reg_mut(dst).bytes.low = result;
+
+        let mut flags = 0u8;
+        if result == 0 {
+            flags |= FLAG_ZERO;
+        }
+        if carry {
+            flags |= FLAG_CARRY;
+        }
+        if overflow {
+            flags |= FLAG_OVERFLOW;
+        }
+        if result & 0b1000_0000 != 0 {
+            flags |= FLAG_SIGN;
+        }
+        self.reg.flags.bytes.low = flags;
+
+        return Ok(());
+    }
+
+    /// sets `ip` to `target`, the index of the instruction to jump to in `.text`
+    fn jump_to(&mut self, target: u16) -> Result<(), Trap> {
+        if target as usize >= self.mem.text.len() {
+            return Err(Trap::IpOutOfBounds { ip: target });
+        }
+
+        self.reg.ip.bytes.low = target as u8;
+        return Ok(());
+    }
+
+    /// pushes `ip` onto the call stack, growing it downward from [`STACK_TOP`]
+    fn push_ip(&mut self, ip: u8) -> Result<(), Trap> {
+        let sp = unsafe { self.reg.sp.full };
+        if sp == 0 {
+            return Err(Trap::StackOverflow);
+        }
+
+        self.mem.data[sp as usize] = ip;
+        self.reg.sp.full = sp - 1;
+        return Ok(());
+    }
+
+    /// pops and returns the `ip` at the top of the call stack
+    fn pop_ip(&mut self) -> Result<u8, Trap> {
+        let sp = unsafe { self.reg.sp.full };
+        if sp == STACK_TOP {
+            return Err(Trap::StackUnderflow);
+        }
+
+        let new_sp = sp + 1;
+        let ip = self.mem.data[new_sp as usize];
+        self.reg.sp.full = new_sp;
+        return Ok(ip);
+    }
+
+    pub fn step(&mut self) -> Result<Op, Trap> {
+        if let Some(trap) = self.trap {
+            return Err(trap);
+        }
+        if self.halted {
+            return Ok(Op::Halt);
+        }
+
+        // fetching the instruction
+        let ip = unsafe { self.reg.ip.bytes.low } as usize;
+        let Some(op_bytes) = self.mem.text.get(ip) else {
+            return self.fault(Trap::IpOutOfBounds { ip: ip as u16 });
+        };
+
         // decoding the instruction
-        let op = unsafe {
-            let op_bytes = &self.mem.text[self.reg.ip.full as usize];
-            transmute(*op_bytes)
+        let op = match Op::decode(op_bytes) {
+            Ok(op) => op,
+            Err(trap) => return self.fault(trap),
         };
 
         // executing the instruction
         match op {
             Op::MoveMemImm { mem_high, mem_low, imm } => {
                 let offset = u16::from_be_bytes([mem_high, mem_low]) as usize;
-                self.mem.data[offset] = imm;
+                let Some(cell) = self.mem.data.get_mut(offset) else {
+                    return self.fault(Trap::MemoryOutOfBounds { offset: offset as u16 });
+                };
+                *cell = imm;
             }
             Op::MoveMemReg { mem_high, mem_low, src } => {
                 let offset = u16::from_be_bytes([mem_high, mem_low]) as usize;
-                self.mem.data[offset] = unsafe { self.reg(src).full };
+                let value = unsafe { self.reg(src).bytes.low };
+                let Some(cell) = self.mem.data.get_mut(offset) else {
+                    return self.fault(Trap::MemoryOutOfBounds { offset: offset as u16 });
+                };
+                *cell = value;
             }
-            Op::MoveRegImm { dst, imm } => self.reg_mut(dst).full = imm,
-            Op::MoveRegReg { dst, src } => self.reg_mut(dst).full = unsafe { self.reg(src).full },
+            Op::MoveRegImm { dst, imm } => self.reg_mut(dst).bytes.low = imm,
+            Op::MoveRegReg { dst, src } => self.reg_mut(dst).bytes.low = unsafe { self.reg(src).bytes.low },
             Op::MoveRegMem { dst, mem_high, mem_low } => {
                 let offset = u16::from_be_bytes([mem_high, mem_low]) as usize;
-                self.reg_mut(dst).full = self.mem.data[offset];
+                let Some(&value) = self.mem.data.get(offset) else {
+                    return self.fault(Trap::MemoryOutOfBounds { offset: offset as u16 });
+                };
+                self.reg_mut(dst).bytes.low = value;
+            }
+            Op::MoveRegMemReg { dst, addr } => {
+                let offset = unsafe { self.reg(addr).full } as usize;
+                let Some(&value) = self.mem.data.get(offset) else {
+                    return self.fault(Trap::MemoryOutOfBounds { offset: offset as u16 });
+                };
+                self.reg_mut(dst).bytes.low = value;
+            }
+            Op::MoveMemRegReg { addr, src } => {
+                let offset = unsafe { self.reg(addr).full } as usize;
+                let value = unsafe { self.reg(src).bytes.low };
+                let Some(cell) = self.mem.data.get_mut(offset) else {
+                    return self.fault(Trap::MemoryOutOfBounds { offset: offset as u16 });
+                };
+                *cell = value;
+            }
+            Op::MoveRegImm16 { dst, imm_high, imm_low } => {
+                self.reg_mut(dst).full = u16::from_be_bytes([imm_high, imm_low]);
+            }
+            Op::AddRegReg { dst, src } => {
+                let rhs = unsafe { self.reg(src).bytes.low };
+                if let Err(trap) = self.execute_alu(AluOp::Add, dst, rhs) {
+                    return self.fault(trap);
+                }
+            }
+            Op::AddRegImm { dst, imm } => {
+                if let Err(trap) = self.execute_alu(AluOp::Add, dst, imm) {
+                    return self.fault(trap);
+                }
+            }
+            Op::SubRegReg { dst, src } => {
+                let rhs = unsafe { self.reg(src).bytes.low };
+                if let Err(trap) = self.execute_alu(AluOp::Sub, dst, rhs) {
+                    return self.fault(trap);
+                }
+            }
+            Op::SubRegImm { dst, imm } => {
+                if let Err(trap) = self.execute_alu(AluOp::Sub, dst, imm) {
+                    return self.fault(trap);
+                }
+            }
+            Op::MulRegReg { dst, src } => {
+                let rhs = unsafe { self.reg(src).bytes.low };
+                if let Err(trap) = self.execute_alu(AluOp::Mul, dst, rhs) {
+                    return self.fault(trap);
+                }
+            }
+            Op::MulRegImm { dst, imm } => {
+                if let Err(trap) = self.execute_alu(AluOp::Mul, dst, imm) {
+                    return self.fault(trap);
+                }
+            }
+            Op::DivRegReg { dst, src } => {
+                let rhs = unsafe { self.reg(src).bytes.low };
+                if let Err(trap) = self.execute_alu(AluOp::Div, dst, rhs) {
+                    return self.fault(trap);
+                }
+            }
+            Op::DivRegImm { dst, imm } => {
+                if let Err(trap) = self.execute_alu(AluOp::Div, dst, imm) {
+                    return self.fault(trap);
+                }
+            }
+            Op::AndRegReg { dst, src } => {
+                let rhs = unsafe { self.reg(src).bytes.low };
+                if let Err(trap) = self.execute_alu(AluOp::And, dst, rhs) {
+                    return self.fault(trap);
+                }
+            }
+            Op::AndRegImm { dst, imm } => {
+                if let Err(trap) = self.execute_alu(AluOp::And, dst, imm) {
+                    return self.fault(trap);
+                }
+            }
+            Op::OrRegReg { dst, src } => {
+                let rhs = unsafe { self.reg(src).bytes.low };
+                if let Err(trap) = self.execute_alu(AluOp::Or, dst, rhs) {
+                    return self.fault(trap);
+                }
+            }
+            Op::OrRegImm { dst, imm } => {
+                if let Err(trap) = self.execute_alu(AluOp::Or, dst, imm) {
+                    return self.fault(trap);
+                }
+            }
+            Op::XorRegReg { dst, src } => {
+                let rhs = unsafe { self.reg(src).bytes.low };
+                if let Err(trap) = self.execute_alu(AluOp::Xor, dst, rhs) {
+                    return self.fault(trap);
+                }
+            }
+            Op::XorRegImm { dst, imm } => {
+                if let Err(trap) = self.execute_alu(AluOp::Xor, dst, imm) {
+                    return self.fault(trap);
+                }
+            }
+            Op::ShlRegReg { dst, src } => {
+                let rhs = unsafe { self.reg(src).bytes.low };
+                if let Err(trap) = self.execute_alu(AluOp::Shl, dst, rhs) {
+                    return self.fault(trap);
+                }
+            }
+            Op::ShlRegImm { dst, imm } => {
+                if let Err(trap) = self.execute_alu(AluOp::Shl, dst, imm) {
+                    return self.fault(trap);
+                }
+            }
+            Op::ShrRegReg { dst, src } => {
+                let rhs = unsafe { self.reg(src).bytes.low };
+                if let Err(trap) = self.execute_alu(AluOp::Shr, dst, rhs) {
+                    return self.fault(trap);
+                }
+            }
+            Op::ShrRegImm { dst, imm } => {
+                if let Err(trap) = self.execute_alu(AluOp::Shr, dst, imm) {
+                    return self.fault(trap);
+                }
+            }
+            Op::Jump { target_high, target_low } => {
+                let target = u16::from_be_bytes([target_high, target_low]);
+                if let Err(trap) = self.jump_to(target) {
+                    return self.fault(trap);
+                }
+                self.cycles += 1;
+                return Ok(op);
+            }
+            Op::JumpZero { target_high, target_low } => {
+                if unsafe { self.reg.flags.bytes.low } & FLAG_ZERO != 0 {
+                    let target = u16::from_be_bytes([target_high, target_low]);
+                    if let Err(trap) = self.jump_to(target) {
+                        return self.fault(trap);
+                    }
+                    self.cycles += 1;
+                    return Ok(op);
+                }
+            }
+            Op::JumpNotZero { target_high, target_low } => {
+                if unsafe { self.reg.flags.bytes.low } & FLAG_ZERO == 0 {
+                    let target = u16::from_be_bytes([target_high, target_low]);
+                    if let Err(trap) = self.jump_to(target) {
+                        return self.fault(trap);
+                    }
+                    self.cycles += 1;
+                    return Ok(op);
+                }
+            }
+            Op::JumpCarry { target_high, target_low } => {
+                if unsafe { self.reg.flags.bytes.low } & FLAG_CARRY != 0 {
+                    let target = u16::from_be_bytes([target_high, target_low]);
+                    if let Err(trap) = self.jump_to(target) {
+                        return self.fault(trap);
+                    }
+                    self.cycles += 1;
+                    return Ok(op);
+                }
+            }
+            Op::JumpSign { target_high, target_low } => {
+                if unsafe { self.reg.flags.bytes.low } & FLAG_SIGN != 0 {
+                    let target = u16::from_be_bytes([target_high, target_low]);
+                    if let Err(trap) = self.jump_to(target) {
+                        return self.fault(trap);
+                    }
+                    self.cycles += 1;
+                    return Ok(op);
+                }
+            }
+            Op::Call { target_high, target_low } => {
+                let return_ip = unsafe { self.reg.ip.bytes.low }.wrapping_add(1);
+                if let Err(trap) = self.push_ip(return_ip) {
+                    return self.fault(trap);
+                }
+
+                let target = u16::from_be_bytes([target_high, target_low]);
+                if let Err(trap) = self.jump_to(target) {
+                    return self.fault(trap);
+                }
+                self.cycles += 1;
+                return Ok(op);
+            }
+            Op::Ret => {
+                let return_ip = match self.pop_ip() {
+                    Ok(ip) => ip,
+                    Err(trap) => return self.fault(trap),
+                };
+                if let Err(trap) = self.jump_to(return_ip as u16) {
+                    return self.fault(trap);
+                }
+                self.cycles += 1;
+                return Ok(op);
+            }
+            Op::Halt => {
+                self.halted = true;
+                self.cycles += 1;
+                return Ok(Op::Halt);
             }
-            Op::Halt => return Op::Halt,
             Op::Nop => {}
         }
 
-        unsafe { self.reg.ip.full += 1 };
-        return op;
+        let ip = unsafe { self.reg.ip.bytes.low };
+        let Some(next_ip) = ip.checked_add(1) else {
+            return self.fault(Trap::IpOutOfBounds { ip: ip as u16 + 1 });
+        };
+        self.reg.ip.bytes.low = next_ip;
+        self.cycles += 1;
+        return Ok(op);
     }
+
+    /// steps the KPU in a loop until it halts, traps, or (if `budget` is `Some`) `budget`
+    /// instructions have been executed, whichever comes first
+    ///
+    /// lets a host drive execution in bounded slices, inspecting the KPU with [`register`],
+    /// [`flags`], [`data`] and [`cycles`] in between calls and resuming with another `run`
+    ///
+    /// [`register`]: Kpu::register
+    /// [`flags`]: Kpu::flags
+    /// [`data`]: Kpu::data
+    /// [`cycles`]: Kpu::cycles
+    pub fn run(&mut self, budget: Option<u64>) -> RunOutcome {
+        let mut executed = 0u64;
+        loop {
+            if budget.is_some_and(|budget| executed >= budget) {
+                return RunOutcome::BudgetExhausted { executed };
+            }
+
+            match self.step() {
+                Ok(Op::Halt) => return RunOutcome::Halted,
+                Ok(_) => executed += 1,
+                Err(trap) => return RunOutcome::Trapped(trap),
+            }
+        }
+    }
+}
+
+/// the result of driving the KPU with [`Kpu::run`] until it stopped, one way or another
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RunOutcome {
+    /// `Op::Halt` was executed
+    Halted,
+
+    /// a trap fired before `budget` instructions (if any) could be executed
+    Trapped(Trap),
+
+    /// `budget` instructions were executed without halting or trapping
+    BudgetExhausted { executed: u64 },
 }
 
 /// Operations available to our KPU
@@ -271,6 +779,501 @@ pub enum Op {
     /// ```
     MoveRegMem { dst: Reg, mem_high: u8, mem_low: u8 } = 0b0010_0010,
 
+    /// Copies the contents at the `.data` offset held by the address register to the specified
+    /// destination register; the address register holds the offset directly, computed at
+    /// runtime, instead of it being encoded as an immediate in the instruction
+    ///
+    /// # Opcode
+    ///
+    /// 0010_0011 0000_dddd 0000_aaaa XXXX_XXXX
+    ///
+    /// - d bits: destination register
+    /// - a bits: address register, holding the `.data` offset to read from
+    /// - X bits: ignored (may possibly contain garbage values)
+    ///
+    /// # Example
+    ///
+    /// ```kasm
+    /// move r0, [r1]
+    /// ```
+    MoveRegMemReg { dst: Reg, addr: Reg } = 0b0010_0011,
+
+    /// Copies the contents of the source register to the `.data` offset held by the address
+    /// register; the address register holds the offset directly, computed at runtime, instead
+    /// of it being encoded as an immediate in the instruction
+    ///
+    /// # Opcode
+    ///
+    /// 0010_0100 0000_aaaa 0000_ssss XXXX_XXXX
+    ///
+    /// - a bits: address register, holding the `.data` offset to write to
+    /// - s bits: source register
+    /// - X bits: ignored (may possibly contain garbage values)
+    ///
+    /// # Example
+    ///
+    /// ```kasm
+    /// move [r1], r0
+    /// ```
+    MoveMemRegReg { addr: Reg, src: Reg } = 0b0010_0100,
+
+    /// Moves an unsigned 16bit integer into the specified destination register, setting its
+    /// full width (unlike [`MoveRegImm`], which only ever reaches the low byte), so a register
+    /// can hold a `.data` offset beyond the low 256 bytes for use with
+    /// [`MoveRegMemReg`]/[`MoveMemRegReg`]
+    ///
+    /// # Opcode
+    ///
+    /// 0010_0101 0000_dddd vvvv_vvvv vvvv_vvvv
+    ///
+    /// - d bits: destination register
+    /// - v bits: unsigned 16bit integer, high byte first
+    ///
+    /// # Example
+    ///
+    /// ```kasm
+    /// move r0, 300
+    /// ```
+    MoveRegImm16 { dst: Reg, imm_high: u8, imm_low: u8 } = 0b0010_0101,
+
+    /// Adds the contents of the source register to the destination register, storing the
+    /// result in the destination register and updating the flags register
+    ///
+    /// # Opcode
+    ///
+    /// 0011_0000 0000_dddd 0000_ssss XXXX_XXXX
+    ///
+    /// - d bits: destination register
+    /// - s bits: source register
+    /// - X bits: ignored (may possibly contain garbage values)
+    ///
+    /// # Example
+    ///
+    /// ```kasm
+    /// add r0, r1
+    /// ```
+    AddRegReg { dst: Reg, src: Reg } = 0b0011_0000,
+
+    /// Subtracts the contents of the source register from the destination register, storing
+    /// the result in the destination register and updating the flags register
+    ///
+    /// # Opcode
+    ///
+    /// 0011_0001 0000_dddd 0000_ssss XXXX_XXXX
+    ///
+    /// - d bits: destination register
+    /// - s bits: source register
+    /// - X bits: ignored (may possibly contain garbage values)
+    ///
+    /// # Example
+    ///
+    /// ```kasm
+    /// sub r0, r1
+    /// ```
+    SubRegReg { dst: Reg, src: Reg } = 0b0011_0001,
+
+    /// Multiplies the destination register by the source register, storing the result in the
+    /// destination register and updating the flags register
+    ///
+    /// # Opcode
+    ///
+    /// 0011_0010 0000_dddd 0000_ssss XXXX_XXXX
+    ///
+    /// - d bits: destination register
+    /// - s bits: source register
+    /// - X bits: ignored (may possibly contain garbage values)
+    ///
+    /// # Example
+    ///
+    /// ```kasm
+    /// mul r0, r1
+    /// ```
+    MulRegReg { dst: Reg, src: Reg } = 0b0011_0010,
+
+    /// Divides the destination register by the source register, storing the result in the
+    /// destination register and updating the flags register; raises [`Trap::DivisionByZero`]
+    /// when the source register is `0`
+    ///
+    /// # Opcode
+    ///
+    /// 0011_0011 0000_dddd 0000_ssss XXXX_XXXX
+    ///
+    /// - d bits: destination register
+    /// - s bits: source register
+    /// - X bits: ignored (may possibly contain garbage values)
+    ///
+    /// # Example
+    ///
+    /// ```kasm
+    /// div r0, r1
+    /// ```
+    DivRegReg { dst: Reg, src: Reg } = 0b0011_0011,
+
+    /// Bitwise-ANDs the destination register with the source register, storing the result in
+    /// the destination register and updating the flags register
+    ///
+    /// # Opcode
+    ///
+    /// 0011_0100 0000_dddd 0000_ssss XXXX_XXXX
+    ///
+    /// - d bits: destination register
+    /// - s bits: source register
+    /// - X bits: ignored (may possibly contain garbage values)
+    ///
+    /// # Example
+    ///
+    /// ```kasm
+    /// and r0, r1
+    /// ```
+    AndRegReg { dst: Reg, src: Reg } = 0b0011_0100,
+
+    /// Bitwise-ORs the destination register with the source register, storing the result in
+    /// the destination register and updating the flags register
+    ///
+    /// # Opcode
+    ///
+    /// 0011_0101 0000_dddd 0000_ssss XXXX_XXXX
+    ///
+    /// - d bits: destination register
+    /// - s bits: source register
+    /// - X bits: ignored (may possibly contain garbage values)
+    ///
+    /// # Example
+    ///
+    /// ```kasm
+    /// or r0, r1
+    /// ```
+    OrRegReg { dst: Reg, src: Reg } = 0b0011_0101,
+
+    /// Bitwise-XORs the destination register with the source register, storing the result in
+    /// the destination register and updating the flags register
+    ///
+    /// # Opcode
+    ///
+    /// 0011_0110 0000_dddd 0000_ssss XXXX_XXXX
+    ///
+    /// - d bits: destination register
+    /// - s bits: source register
+    /// - X bits: ignored (may possibly contain garbage values)
+    ///
+    /// # Example
+    ///
+    /// ```kasm
+    /// xor r0, r1
+    /// ```
+    XorRegReg { dst: Reg, src: Reg } = 0b0011_0110,
+
+    /// Shifts the destination register left by the amount in the source register, storing the
+    /// result in the destination register and updating the flags register
+    ///
+    /// # Opcode
+    ///
+    /// 0011_0111 0000_dddd 0000_ssss XXXX_XXXX
+    ///
+    /// - d bits: destination register
+    /// - s bits: source register
+    /// - X bits: ignored (may possibly contain garbage values)
+    ///
+    /// # Example
+    ///
+    /// ```kasm
+    /// shl r0, r1
+    /// ```
+    ShlRegReg { dst: Reg, src: Reg } = 0b0011_0111,
+
+    /// Shifts the destination register right by the amount in the source register, storing the
+    /// result in the destination register and updating the flags register
+    ///
+    /// # Opcode
+    ///
+    /// 0011_1000 0000_dddd 0000_ssss XXXX_XXXX
+    ///
+    /// - d bits: destination register
+    /// - s bits: source register
+    /// - X bits: ignored (may possibly contain garbage values)
+    ///
+    /// # Example
+    ///
+    /// ```kasm
+    /// shr r0, r1
+    /// ```
+    ShrRegReg { dst: Reg, src: Reg } = 0b0011_1000,
+
+    /// Adds a signed 8bit integer to the destination register, storing the result in the
+    /// destination register and updating the flags register
+    ///
+    /// # Opcode
+    ///
+    /// 0100_0000 0000_dddd vvvv_vvvv XXXX_XXXX
+    ///
+    /// - d bits: destination register
+    /// - v bits: signed 8bit integer
+    /// - X bits: ignored (may possibly contain garbage values)
+    ///
+    /// # Example
+    ///
+    /// ```kasm
+    /// add r0, 5
+    /// ```
+    AddRegImm { dst: Reg, imm: u8 } = 0b0100_0000,
+
+    /// Subtracts a signed 8bit integer from the destination register, storing the result in the
+    /// destination register and updating the flags register
+    ///
+    /// # Opcode
+    ///
+    /// 0100_0001 0000_dddd vvvv_vvvv XXXX_XXXX
+    ///
+    /// - d bits: destination register
+    /// - v bits: signed 8bit integer
+    /// - X bits: ignored (may possibly contain garbage values)
+    ///
+    /// # Example
+    ///
+    /// ```kasm
+    /// sub r0, 5
+    /// ```
+    SubRegImm { dst: Reg, imm: u8 } = 0b0100_0001,
+
+    /// Multiplies the destination register by a signed 8bit integer, storing the result in the
+    /// destination register and updating the flags register
+    ///
+    /// # Opcode
+    ///
+    /// 0100_0010 0000_dddd vvvv_vvvv XXXX_XXXX
+    ///
+    /// - d bits: destination register
+    /// - v bits: signed 8bit integer
+    /// - X bits: ignored (may possibly contain garbage values)
+    ///
+    /// # Example
+    ///
+    /// ```kasm
+    /// mul r0, 5
+    /// ```
+    MulRegImm { dst: Reg, imm: u8 } = 0b0100_0010,
+
+    /// Divides the destination register by a signed 8bit integer, storing the result in the
+    /// destination register and updating the flags register; raises
+    /// [`Trap::DivisionByZero`] when the immediate is `0`
+    ///
+    /// # Opcode
+    ///
+    /// 0100_0011 0000_dddd vvvv_vvvv XXXX_XXXX
+    ///
+    /// - d bits: destination register
+    /// - v bits: signed 8bit integer
+    /// - X bits: ignored (may possibly contain garbage values)
+    ///
+    /// # Example
+    ///
+    /// ```kasm
+    /// div r0, 5
+    /// ```
+    DivRegImm { dst: Reg, imm: u8 } = 0b0100_0011,
+
+    /// Bitwise-ANDs the destination register with a signed 8bit integer, storing the result in
+    /// the destination register and updating the flags register
+    ///
+    /// # Opcode
+    ///
+    /// 0100_0100 0000_dddd vvvv_vvvv XXXX_XXXX
+    ///
+    /// - d bits: destination register
+    /// - v bits: signed 8bit integer
+    /// - X bits: ignored (may possibly contain garbage values)
+    ///
+    /// # Example
+    ///
+    /// ```kasm
+    /// and r0, 5
+    /// ```
+    AndRegImm { dst: Reg, imm: u8 } = 0b0100_0100,
+
+    /// Bitwise-ORs the destination register with a signed 8bit integer, storing the result in
+    /// the destination register and updating the flags register
+    ///
+    /// # Opcode
+    ///
+    /// 0100_0101 0000_dddd vvvv_vvvv XXXX_XXXX
+    ///
+    /// - d bits: destination register
+    /// - v bits: signed 8bit integer
+    /// - X bits: ignored (may possibly contain garbage values)
+    ///
+    /// # Example
+    ///
+    /// ```kasm
+    /// or r0, 5
+    /// ```
+    OrRegImm { dst: Reg, imm: u8 } = 0b0100_0101,
+
+    /// Bitwise-XORs the destination register with a signed 8bit integer, storing the result in
+    /// the destination register and updating the flags register
+    ///
+    /// # Opcode
+    ///
+    /// 0100_0110 0000_dddd vvvv_vvvv XXXX_XXXX
+    ///
+    /// - d bits: destination register
+    /// - v bits: signed 8bit integer
+    /// - X bits: ignored (may possibly contain garbage values)
+    ///
+    /// # Example
+    ///
+    /// ```kasm
+    /// xor r0, 5
+    /// ```
+    XorRegImm { dst: Reg, imm: u8 } = 0b0100_0110,
+
+    /// Shifts the destination register left by a signed 8bit integer, storing the result in
+    /// the destination register and updating the flags register
+    ///
+    /// # Opcode
+    ///
+    /// 0100_0111 0000_dddd vvvv_vvvv XXXX_XXXX
+    ///
+    /// - d bits: destination register
+    /// - v bits: signed 8bit integer
+    /// - X bits: ignored (may possibly contain garbage values)
+    ///
+    /// # Example
+    ///
+    /// ```kasm
+    /// shl r0, 5
+    /// ```
+    ShlRegImm { dst: Reg, imm: u8 } = 0b0100_0111,
+
+    /// Shifts the destination register right by a signed 8bit integer, storing the result in
+    /// the destination register and updating the flags register
+    ///
+    /// # Opcode
+    ///
+    /// 0100_1000 0000_dddd vvvv_vvvv XXXX_XXXX
+    ///
+    /// - d bits: destination register
+    /// - v bits: signed 8bit integer
+    /// - X bits: ignored (may possibly contain garbage values)
+    ///
+    /// # Example
+    ///
+    /// ```kasm
+    /// shr r0, 5
+    /// ```
+    ShrRegImm { dst: Reg, imm: u8 } = 0b0100_1000,
+
+    /// Unconditionally sets `ip` to the specified `.text` section instruction index
+    ///
+    /// # Opcode
+    ///
+    /// 0101_0000 tttt_tttt tttt_tttt XXXX_XXXX
+    ///
+    /// - t bits: target instruction index
+    /// - X bits: ignored (may possibly contain garbage values)
+    ///
+    /// # Example
+    ///
+    /// ```kasm
+    /// jump 5
+    /// ```
+    Jump { target_high: u8, target_low: u8 } = 0b0101_0000,
+
+    /// Sets `ip` to the specified `.text` section instruction index if the zero flag is set
+    ///
+    /// # Opcode
+    ///
+    /// 0101_0001 tttt_tttt tttt_tttt XXXX_XXXX
+    ///
+    /// - t bits: target instruction index
+    /// - X bits: ignored (may possibly contain garbage values)
+    ///
+    /// # Example
+    ///
+    /// ```kasm
+    /// jz 5
+    /// ```
+    JumpZero { target_high: u8, target_low: u8 } = 0b0101_0001,
+
+    /// Sets `ip` to the specified `.text` section instruction index if the zero flag is clear
+    ///
+    /// # Opcode
+    ///
+    /// 0101_0010 tttt_tttt tttt_tttt XXXX_XXXX
+    ///
+    /// - t bits: target instruction index
+    /// - X bits: ignored (may possibly contain garbage values)
+    ///
+    /// # Example
+    ///
+    /// ```kasm
+    /// jnz 5
+    /// ```
+    JumpNotZero { target_high: u8, target_low: u8 } = 0b0101_0010,
+
+    /// Sets `ip` to the specified `.text` section instruction index if the carry flag is set
+    ///
+    /// # Opcode
+    ///
+    /// 0101_0011 tttt_tttt tttt_tttt XXXX_XXXX
+    ///
+    /// - t bits: target instruction index
+    /// - X bits: ignored (may possibly contain garbage values)
+    ///
+    /// # Example
+    ///
+    /// ```kasm
+    /// jc 5
+    /// ```
+    JumpCarry { target_high: u8, target_low: u8 } = 0b0101_0011,
+
+    /// Sets `ip` to the specified `.text` section instruction index if the sign flag is set
+    ///
+    /// # Opcode
+    ///
+    /// 0101_0100 tttt_tttt tttt_tttt XXXX_XXXX
+    ///
+    /// - t bits: target instruction index
+    /// - X bits: ignored (may possibly contain garbage values)
+    ///
+    /// # Example
+    ///
+    /// ```kasm
+    /// js 5
+    /// ```
+    JumpSign { target_high: u8, target_low: u8 } = 0b0101_0100,
+
+    /// Pushes the instruction index following this one onto the call stack and sets `ip` to
+    /// the specified `.text` section instruction index
+    ///
+    /// # Opcode
+    ///
+    /// 0101_0101 tttt_tttt tttt_tttt XXXX_XXXX
+    ///
+    /// - t bits: target instruction index
+    /// - X bits: ignored (may possibly contain garbage values)
+    ///
+    /// # Example
+    ///
+    /// ```kasm
+    /// call 7
+    /// ```
+    Call { target_high: u8, target_low: u8 } = 0b0101_0101,
+
+    /// Pops the instruction index at the top of the call stack and sets `ip` to it
+    ///
+    /// # Opcode
+    ///
+    /// 0101_0110 XXXX_XXXX XXXX_XXXX XXXX_XXXX
+    ///
+    /// - X bits: ignored (may possibly contain garbage values)
+    ///
+    /// # Example
+    ///
+    /// ```kasm
+    /// ret
+    /// ```
+    Ret = 0b0101_0110,
+
     /// Stops the execution of the processor
     ///
     /// # Opcode
@@ -289,7 +1292,7 @@ pub enum Op {
 
 impl Display for Op {
     /// Source coude view
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         return match self {
             Self::Nop => write!(f, "nop"),
             Self::Halt => write!(f, "halt"),
@@ -303,7 +1306,49 @@ impl Display for Op {
             Self::MoveRegMem { dst, mem_high, mem_low } => {
                 write!(f, "move {}, [{}]", dst, u16::from_be_bytes([*mem_high, *mem_low]))
             }
+            Self::MoveRegMemReg { dst, addr } => write!(f, "move {}, [{}]", dst, addr),
+            Self::MoveMemRegReg { addr, src } => write!(f, "move [{}], {}", addr, src),
+            Self::MoveRegImm16 { dst, imm_high, imm_low } => {
+                write!(f, "move {}, {}", dst, u16::from_be_bytes([*imm_high, *imm_low]))
+            }
             Self::MoveRegReg { dst, src } => write!(f, "move {}, {}", dst, src),
+            Self::AddRegReg { dst, src } => write!(f, "add {}, {}", dst, src),
+            Self::AddRegImm { dst, imm } => write!(f, "add {}, {}", dst, imm),
+            Self::SubRegReg { dst, src } => write!(f, "sub {}, {}", dst, src),
+            Self::SubRegImm { dst, imm } => write!(f, "sub {}, {}", dst, imm),
+            Self::MulRegReg { dst, src } => write!(f, "mul {}, {}", dst, src),
+            Self::MulRegImm { dst, imm } => write!(f, "mul {}, {}", dst, imm),
+            Self::DivRegReg { dst, src } => write!(f, "div {}, {}", dst, src),
+            Self::DivRegImm { dst, imm } => write!(f, "div {}, {}", dst, imm),
+            Self::AndRegReg { dst, src } => write!(f, "and {}, {}", dst, src),
+            Self::AndRegImm { dst, imm } => write!(f, "and {}, {}", dst, imm),
+            Self::OrRegReg { dst, src } => write!(f, "or {}, {}", dst, src),
+            Self::OrRegImm { dst, imm } => write!(f, "or {}, {}", dst, imm),
+            Self::XorRegReg { dst, src } => write!(f, "xor {}, {}", dst, src),
+            Self::XorRegImm { dst, imm } => write!(f, "xor {}, {}", dst, imm),
+            Self::ShlRegReg { dst, src } => write!(f, "shl {}, {}", dst, src),
+            Self::ShlRegImm { dst, imm } => write!(f, "shl {}, {}", dst, imm),
+            Self::ShrRegReg { dst, src } => write!(f, "shr {}, {}", dst, src),
+            Self::ShrRegImm { dst, imm } => write!(f, "shr {}, {}", dst, imm),
+            Self::Jump { target_high, target_low } => {
+                write!(f, "jump {}", u16::from_be_bytes([*target_high, *target_low]))
+            }
+            Self::JumpZero { target_high, target_low } => {
+                write!(f, "jz {}", u16::from_be_bytes([*target_high, *target_low]))
+            }
+            Self::JumpNotZero { target_high, target_low } => {
+                write!(f, "jnz {}", u16::from_be_bytes([*target_high, *target_low]))
+            }
+            Self::JumpCarry { target_high, target_low } => {
+                write!(f, "jc {}", u16::from_be_bytes([*target_high, *target_low]))
+            }
+            Self::JumpSign { target_high, target_low } => {
+                write!(f, "js {}", u16::from_be_bytes([*target_high, *target_low]))
+            }
+            Self::Call { target_high, target_low } => {
+                write!(f, "call {}", u16::from_be_bytes([*target_high, *target_low]))
+            }
+            Self::Ret => write!(f, "ret"),
         };
     }
 }
@@ -313,7 +1358,90 @@ const OP_SIZE: usize = size_of::<Op>();
 impl Op {
     #[inline]
     pub fn bytes(self) -> [u8; OP_SIZE] {
-        return unsafe { transmute(self) };
+        return unsafe { transmute::<Self, [u8; OP_SIZE]>(self) };
+    }
+
+    /// Checked decoding of a raw opcode: matches on the opcode byte and only reinterprets the
+    /// remaining payload bytes for opcodes we actually know about, instead of blindly
+    /// transmuting attacker/garbage-controlled bytes into an `Op`
+    pub fn decode(bytes: &[u8; OP_SIZE]) -> Result<Self, Trap> {
+        let [opcode, a, b, c] = *bytes;
+
+        return match opcode {
+            0b0000_0000 => Ok(Self::Nop),
+            0b0001_0000 => {
+                let dst = Reg::decode(a & 0b0000_1111).ok_or(Trap::InvalidOpcode { byte: opcode })?;
+                Ok(Self::MoveRegImm { dst, imm: b })
+            }
+            0b0001_0001 => {
+                let dst = Reg::decode(a & 0b0000_1111).ok_or(Trap::InvalidOpcode { byte: opcode })?;
+                let src = Reg::decode(b & 0b0000_1111).ok_or(Trap::InvalidOpcode { byte: opcode })?;
+                Ok(Self::MoveRegReg { dst, src })
+            }
+            0b0010_0000 => Ok(Self::MoveMemImm { mem_high: a, mem_low: b, imm: c }),
+            0b0010_0001 => {
+                let src = Reg::decode(c & 0b0000_1111).ok_or(Trap::InvalidOpcode { byte: opcode })?;
+                Ok(Self::MoveMemReg { mem_high: a, mem_low: b, src })
+            }
+            0b0010_0010 => {
+                let dst = Reg::decode(a & 0b0000_1111).ok_or(Trap::InvalidOpcode { byte: opcode })?;
+                Ok(Self::MoveRegMem { dst, mem_high: b, mem_low: c })
+            }
+            0b0010_0011 => {
+                let dst = Reg::decode(a & 0b0000_1111).ok_or(Trap::InvalidOpcode { byte: opcode })?;
+                let addr = Reg::decode(b & 0b0000_1111).ok_or(Trap::InvalidOpcode { byte: opcode })?;
+                Ok(Self::MoveRegMemReg { dst, addr })
+            }
+            0b0010_0100 => {
+                let addr = Reg::decode(a & 0b0000_1111).ok_or(Trap::InvalidOpcode { byte: opcode })?;
+                let src = Reg::decode(b & 0b0000_1111).ok_or(Trap::InvalidOpcode { byte: opcode })?;
+                Ok(Self::MoveMemRegReg { addr, src })
+            }
+            0b0010_0101 => {
+                let dst = Reg::decode(a & 0b0000_1111).ok_or(Trap::InvalidOpcode { byte: opcode })?;
+                Ok(Self::MoveRegImm16 { dst, imm_high: b, imm_low: c })
+            }
+            0b0011_0000..=0b0011_1000 => {
+                let dst = Reg::decode(a & 0b0000_1111).ok_or(Trap::InvalidOpcode { byte: opcode })?;
+                let src = Reg::decode(b & 0b0000_1111).ok_or(Trap::InvalidOpcode { byte: opcode })?;
+                match opcode & 0b0000_1111 {
+                    0b0000 => Ok(Self::AddRegReg { dst, src }),
+                    0b0001 => Ok(Self::SubRegReg { dst, src }),
+                    0b0010 => Ok(Self::MulRegReg { dst, src }),
+                    0b0011 => Ok(Self::DivRegReg { dst, src }),
+                    0b0100 => Ok(Self::AndRegReg { dst, src }),
+                    0b0101 => Ok(Self::OrRegReg { dst, src }),
+                    0b0110 => Ok(Self::XorRegReg { dst, src }),
+                    0b0111 => Ok(Self::ShlRegReg { dst, src }),
+                    0b1000 => Ok(Self::ShrRegReg { dst, src }),
+                    _ => Err(Trap::InvalidOpcode { byte: opcode }),
+                }
+            }
+            0b0100_0000..=0b0100_1000 => {
+                let dst = Reg::decode(a & 0b0000_1111).ok_or(Trap::InvalidOpcode { byte: opcode })?;
+                match opcode & 0b0000_1111 {
+                    0b0000 => Ok(Self::AddRegImm { dst, imm: b }),
+                    0b0001 => Ok(Self::SubRegImm { dst, imm: b }),
+                    0b0010 => Ok(Self::MulRegImm { dst, imm: b }),
+                    0b0011 => Ok(Self::DivRegImm { dst, imm: b }),
+                    0b0100 => Ok(Self::AndRegImm { dst, imm: b }),
+                    0b0101 => Ok(Self::OrRegImm { dst, imm: b }),
+                    0b0110 => Ok(Self::XorRegImm { dst, imm: b }),
+                    0b0111 => Ok(Self::ShlRegImm { dst, imm: b }),
+                    0b1000 => Ok(Self::ShrRegImm { dst, imm: b }),
+                    _ => Err(Trap::InvalidOpcode { byte: opcode }),
+                }
+            }
+            0b0101_0000 => Ok(Self::Jump { target_high: a, target_low: b }),
+            0b0101_0001 => Ok(Self::JumpZero { target_high: a, target_low: b }),
+            0b0101_0010 => Ok(Self::JumpNotZero { target_high: a, target_low: b }),
+            0b0101_0011 => Ok(Self::JumpCarry { target_high: a, target_low: b }),
+            0b0101_0100 => Ok(Self::JumpSign { target_high: a, target_low: b }),
+            0b0101_0101 => Ok(Self::Call { target_high: a, target_low: b }),
+            0b0101_0110 => Ok(Self::Ret),
+            0b1111_1111 => Ok(Self::Halt),
+            _ => Err(Trap::InvalidOpcode { byte: opcode }),
+        };
     }
 }
 
@@ -323,7 +1451,7 @@ pub enum LoadError {
 }
 
 impl Display for LoadError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         return match self {
             Self::Size { size_of_loaded_program } => write!(
                 f,
@@ -334,4 +1462,41 @@ impl Display for LoadError {
     }
 }
 
+#[cfg(feature = "std")]
 impl Error for LoadError {}
+
+/// Faults raised by the KPU when it cannot safely execute the next instruction: an unknown
+/// opcode byte, an `ip` pointing past the end of the `.text` section, a memory operand
+/// pointing past the end of the `.data` section, a division by zero, or a call stack
+/// overflow/underflow
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trap {
+    InvalidOpcode { byte: u8 },
+    IpOutOfBounds { ip: u16 },
+    MemoryOutOfBounds { offset: u16 },
+    DivisionByZero,
+
+    /// `Call` was executed with no room left to push a return `ip` onto the call stack
+    StackOverflow,
+
+    /// `Ret` was executed with no return `ip` left on the call stack to pop
+    StackUnderflow,
+}
+
+impl Display for Trap {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        return match self {
+            Self::InvalidOpcode { byte } => write!(f, "invalid opcode '{:#010b}'", byte),
+            Self::IpOutOfBounds { ip } => write!(f, "ip '{}' is out of bounds of the .text section", ip),
+            Self::MemoryOutOfBounds { offset } => {
+                write!(f, "memory offset '{}' is out of bounds of the .data section", offset)
+            }
+            Self::DivisionByZero => write!(f, "attempt to divide by zero"),
+            Self::StackOverflow => write!(f, "call stack overflow"),
+            Self::StackUnderflow => write!(f, "call stack underflow"),
+        };
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for Trap {}